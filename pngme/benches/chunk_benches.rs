@@ -0,0 +1,63 @@
+#![feature(test)]
+
+extern crate test;
+
+use pngme::{Chunk, ChunkType};
+use std::convert::TryFrom;
+use std::str::FromStr;
+use test::Bencher;
+
+fn payload(size: usize) -> Vec<u8> {
+    vec![0u8; size]
+}
+
+fn chunk_type() -> ChunkType {
+    ChunkType::from_str("ReAd").unwrap()
+}
+
+fn bench_new(b: &mut Bencher, size: usize) {
+    let data = payload(size);
+    b.iter(|| Chunk::new(chunk_type(), data.clone()));
+}
+
+fn bench_as_bytes(b: &mut Bencher, size: usize) {
+    let chunk = Chunk::new(chunk_type(), payload(size));
+    b.iter(|| chunk.as_bytes());
+}
+
+fn bench_try_from(b: &mut Bencher, size: usize) {
+    let bytes = Chunk::new(chunk_type(), payload(size)).as_bytes();
+    b.iter(|| Chunk::try_from(bytes.as_slice()).unwrap());
+}
+
+macro_rules! bench_sizes {
+    ($mod_name:ident, $bench_fn:ident) => {
+        mod $mod_name {
+            use super::*;
+
+            #[bench]
+            fn size_1kib(b: &mut Bencher) {
+                $bench_fn(b, 1024);
+            }
+
+            #[bench]
+            fn size_64kib(b: &mut Bencher) {
+                $bench_fn(b, 64 * 1024);
+            }
+
+            #[bench]
+            fn size_1mib(b: &mut Bencher) {
+                $bench_fn(b, 1024 * 1024);
+            }
+
+            #[bench]
+            fn size_8mib(b: &mut Bencher) {
+                $bench_fn(b, 8 * 1024 * 1024);
+            }
+        }
+    };
+}
+
+bench_sizes!(new, bench_new);
+bench_sizes!(as_bytes, bench_as_bytes);
+bench_sizes!(try_from, bench_try_from);
@@ -1,6 +1,7 @@
 use crate::chunk_type::ChunkType;
+use crate::codec::{Decode, Encode};
 use crate::{Error, StrError};
-use crc::crc32::checksum_ieee;
+use crc::crc32::{checksum_ieee, Digest, Hasher32, IEEE};
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
 
@@ -12,38 +13,32 @@ pub struct Chunk {
     crc: u32,
 }
 
-impl<'a> Chunk {
+impl Chunk {
     pub fn new(chunk_type: ChunkType, data: Vec<u8>) -> Self {
-        let chunk_type_and_data_bytes: Vec<u8> = chunk_type
-            .bytes()
-            .iter()
-            .chain(data.iter())
-            .copied()
-            .collect::<Vec<u8>>();
-
-        let crc = checksum_ieee(chunk_type_and_data_bytes.as_ref());
-        let length: u32 = data.len() as u32;
+        let length = data.len() as u32;
 
-        let value: Vec<u8> = length
-            .to_be_bytes()
-            .iter()
-            .chain(chunk_type_and_data_bytes.iter())
-            .chain(crc.to_be_bytes().iter())
-            .copied()
-            .collect();
+        // Feed the type and data directly into the digest instead of
+        // concatenating them into an intermediate buffer first.
+        let mut digest = Digest::new(IEEE);
+        digest.write(&chunk_type.bytes());
+        digest.write(&data);
+        let crc = digest.sum32();
 
-        Self::try_from(value.as_ref()).unwrap()
+        Self {
+            length,
+            chunk_type,
+            data,
+            crc,
+        }
     }
 
     pub fn as_bytes(&self) -> Vec<u8> {
-        self.length
-            .to_be_bytes()
-            .iter()
-            .chain(self.chunk_type.bytes().iter())
-            .chain(self.data.iter())
-            .chain(self.crc().to_be_bytes().iter())
-            .copied()
-            .collect()
+        let mut bytes = Vec::with_capacity(12 + self.data.len());
+        bytes.extend_from_slice(&self.length.to_be_bytes());
+        bytes.extend_from_slice(&self.chunk_type.bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes.extend_from_slice(&self.crc.to_be_bytes());
+        bytes
     }
 
     pub fn length(&self) -> u32 {
@@ -61,6 +56,18 @@ impl<'a> Chunk {
     pub fn crc(&self) -> u32 {
         self.crc
     }
+
+    /// Builds a chunk whose data is the `Encode`d representation of `value`,
+    /// so application-defined chunk types can carry typed payloads instead
+    /// of raw bytes.
+    pub fn new_with<T: Encode>(chunk_type: ChunkType, value: &T) -> Self {
+        Self::new(chunk_type, value.encode())
+    }
+
+    /// Parses this chunk's data back into a typed value via `Decode`.
+    pub fn data_as<T: Decode>(&self) -> Result<T, Error> {
+        T::decode(&self.data)
+    }
 }
 
 impl TryFrom<&[u8]> for Chunk {
@@ -76,7 +83,7 @@ impl TryFrom<&[u8]> for Chunk {
 
         let (chunk_type_bytes, rest) = rest.split_at(4);
         let chunk_type_bytes: [u8; 4] = chunk_type_bytes.try_into().unwrap();
-        let chunk_type = ChunkType::try_from(chunk_type_bytes).unwrap();
+        let chunk_type = ChunkType::try_from(chunk_type_bytes).map_err(Error::from)?;
 
         let (data_bytes, crc_bytes) = rest.split_at(rest.len() - 4);
         let crc: u32 = u32::from_be_bytes(crc_bytes.try_into().unwrap());
@@ -108,6 +115,7 @@ impl fmt::Display for Chunk {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::str::FromStr;
 
     fn testing_chunk() -> Chunk {
         let data_length: u32 = 42;
@@ -201,6 +209,25 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_from_bytes_with_non_ascii_type_returns_err() {
+        let data_length: u32 = 0u32;
+        let chunk_type = [0xFFu8, 0x80, 0x81, 0x82];
+        let crc: u32 = 0;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let chunk = Chunk::try_from(chunk_data.as_ref());
+
+        assert!(chunk.is_err());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
@@ -221,4 +248,24 @@ mod tests {
 
         let _chunk_string = format!("{}", chunk);
     }
+
+    #[test]
+    fn test_new_with_and_data_as_string() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let value = String::from("This is where your secret message will be!");
+
+        let chunk = Chunk::new_with(chunk_type, &value);
+
+        assert_eq!(chunk.data_as::<String>().unwrap(), value);
+    }
+
+    #[test]
+    fn test_new_with_and_data_as_u32() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        let value: u32 = 0xdeadbeef;
+
+        let chunk = Chunk::new_with(chunk_type, &value);
+
+        assert_eq!(chunk.data_as::<u32>().unwrap(), value);
+    }
 }
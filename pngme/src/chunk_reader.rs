@@ -0,0 +1,171 @@
+use crate::chunk::Chunk;
+use crate::png::Png;
+use crate::{Result, StrError};
+use std::convert::TryFrom;
+use std::io::BufRead;
+
+/// Decodes a sequence of `Chunk`s from a `BufRead` without buffering the
+/// whole file, so multi-megabyte PNGs can be processed a chunk at a time.
+pub struct ChunkReader<R: BufRead> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: BufRead> ChunkReader<R> {
+    pub fn new(mut reader: R) -> Result<Self> {
+        let mut signature = [0u8; 8];
+        reader.read_exact(&mut signature)?;
+
+        if signature != Png::STANDARD_HEADER {
+            return Err(Box::new(StrError("Invalid PNG signature")));
+        }
+
+        Ok(Self {
+            reader,
+            done: false,
+        })
+    }
+
+    fn read_chunk(&mut self) -> Result<Option<Chunk>> {
+        let mut length_bytes = [0u8; 4];
+        match self.reader.read_exact(&mut length_bytes) {
+            Ok(()) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => return Err(err.into()),
+        }
+
+        let data_length = u32::from_be_bytes(length_bytes) as usize;
+
+        let mut type_bytes = [0u8; 4];
+        self.reader.read_exact(&mut type_bytes)?;
+
+        let mut data = vec![0u8; data_length];
+        self.reader.read_exact(&mut data)?;
+
+        let mut crc_bytes = [0u8; 4];
+        self.reader.read_exact(&mut crc_bytes)?;
+
+        let chunk_bytes: Vec<u8> = length_bytes
+            .iter()
+            .chain(type_bytes.iter())
+            .chain(data.iter())
+            .chain(crc_bytes.iter())
+            .copied()
+            .collect();
+
+        Chunk::try_from(chunk_bytes.as_ref()).map(Some)
+    }
+}
+
+impl<R: BufRead> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.read_chunk() {
+            Ok(Some(chunk)) => {
+                if chunk.chunk_type().to_string() == "IEND" {
+                    self.done = true;
+                }
+                Some(Ok(chunk))
+            }
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::io::Cursor;
+    use std::str::FromStr;
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Chunk {
+        let chunk_type = ChunkType::from_str(chunk_type).unwrap();
+        let data: Vec<u8> = data.bytes().collect();
+
+        Chunk::new(chunk_type, data)
+    }
+
+    fn testing_png_bytes() -> Vec<u8> {
+        let chunks = vec![
+            chunk_from_strings("FrSt", "I am the first chunk"),
+            chunk_from_strings("miDl", "I am another chunk"),
+            chunk_from_strings("IEND", ""),
+        ];
+
+        Png::STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(chunks.into_iter().flat_map(|chunk| chunk.as_bytes()))
+            .collect()
+    }
+
+    #[test]
+    fn test_reads_all_chunks_in_order() {
+        let bytes = testing_png_bytes();
+        let reader = ChunkReader::new(Cursor::new(bytes)).unwrap();
+        let chunks: Vec<Chunk> = reader.map(Result::unwrap).collect();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].chunk_type().to_string(), String::from("FrSt"));
+        assert_eq!(chunks[2].chunk_type().to_string(), String::from("IEND"));
+    }
+
+    #[test]
+    fn test_stops_after_iend() {
+        let mut bytes = testing_png_bytes();
+        bytes.extend(chunk_from_strings("LASt", "unreachable").as_bytes());
+
+        let reader = ChunkReader::new(Cursor::new(bytes)).unwrap();
+        let chunks: Vec<Chunk> = reader.map(Result::unwrap).collect();
+
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[test]
+    fn test_rejects_invalid_signature() {
+        let mut bytes = testing_png_bytes();
+        bytes[0] = 13;
+
+        assert!(ChunkReader::new(Cursor::new(bytes)).is_err());
+    }
+
+    #[test]
+    fn test_clean_eof_at_chunk_boundary() {
+        // Drop the trailing 12-byte IEND chunk so the stream ends right
+        // after a complete chunk instead of at an IEND terminator.
+        let mut bytes = testing_png_bytes();
+        let boundary_len = bytes.len() - 12;
+        bytes.truncate(boundary_len);
+        let mut reader = ChunkReader::new(Cursor::new(bytes)).unwrap();
+
+        while let Some(chunk) = reader.next() {
+            chunk.unwrap();
+        }
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_error_on_truncated_chunk() {
+        let mut bytes = testing_png_bytes();
+        let truncated_len = bytes.len() - 5;
+        bytes.truncate(truncated_len);
+
+        let reader = ChunkReader::new(Cursor::new(bytes)).unwrap();
+        let results: Vec<Result<Chunk>> = reader.collect();
+
+        assert!(results.last().unwrap().is_err());
+    }
+}
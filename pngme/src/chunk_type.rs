@@ -40,17 +40,69 @@ impl ChunkType {
     pub fn is_safe_to_copy(&self) -> bool {
         self.bytes[3] & 1 << 5 != 0
     }
+
+    /// Sets or clears bit 5 of `self.bytes[index]`, the bit every property
+    /// flag below is encoded in, and returns the updated type.
+    fn with_bit(mut self, index: usize, set: bool) -> Self {
+        if set {
+            self.bytes[index] |= 1 << 5;
+        } else {
+            self.bytes[index] &= !(1 << 5);
+        }
+        self
+    }
+
+    /// Returns a `ChunkType` with the first character's case set so that
+    /// `is_critical()` reports `critical`.
+    pub fn set_critical(self, critical: bool) -> Self {
+        self.with_bit(0, !critical)
+    }
+
+    /// Returns a `ChunkType` with the second character's case set so that
+    /// `is_public()` reports `public`.
+    pub fn set_public(self, public: bool) -> Self {
+        self.with_bit(1, !public)
+    }
+
+    /// Returns a `ChunkType` with the third character's case set so that
+    /// `is_reserved_bit_valid()` reports `valid`.
+    pub fn set_reserved_valid(self, valid: bool) -> Self {
+        self.with_bit(2, !valid)
+    }
+
+    /// Returns a `ChunkType` with the fourth character's case set so that
+    /// `is_safe_to_copy()` reports `safe_to_copy`.
+    pub fn set_safe_to_copy(self, safe_to_copy: bool) -> Self {
+        self.with_bit(3, safe_to_copy)
+    }
+
+    /// Builds a `ChunkType` from four ASCII letters, then flips the
+    /// property bits to match `critical`/`public`/`safe_to_copy`. The
+    /// reserved bit is always left valid, as the PNG spec requires.
+    pub fn from_properties(letters: [char; 4], critical: bool, public: bool, safe_to_copy: bool) -> Self {
+        assert!(
+            letters.iter().all(|c| c.is_ascii_alphabetic()),
+            "ChunkType::from_properties letters must all be ASCII alphabetic"
+        );
+
+        let bytes: [u8; 4] = [
+            letters[0] as u8,
+            letters[1] as u8,
+            letters[2] as u8,
+            letters[3] as u8,
+        ];
+
+        Self { bytes }
+            .set_critical(critical)
+            .set_public(public)
+            .set_reserved_valid(true)
+            .set_safe_to_copy(safe_to_copy)
+    }
 }
 
 impl fmt::Display for ChunkType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            str::from_utf8(&self.bytes)
-                .expect("Invalid UTF-8")
-                .to_string()
-        )
+        write!(f, "{}", str::from_utf8(&self.bytes).expect("Invalid UTF-8"))
     }
 }
 
@@ -60,7 +112,7 @@ impl FromStr for ChunkType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let bytes: [u8; 4] = s.as_bytes().try_into().expect("str with length 4");
 
-        if !bytes.iter().map(|b| b.is_ascii_alphabetic()).all(|b| b) {
+        if !bytes.iter().all(|b| b.is_ascii_alphabetic()) {
             return Err("ASCII alphabets only bish".into());
         }
 
@@ -72,7 +124,7 @@ impl TryFrom<[u8; 4]> for ChunkType {
     type Error = String;
 
     fn try_from(value: [u8; 4]) -> Result<Self, Self::Error> {
-        if let true = value.iter().map(u8::is_ascii).all(|v| v) {
+        if value.iter().all(u8::is_ascii) {
             Ok(Self { bytes: value })
         } else {
             Err("Invalid ascii byte found".to_string())
@@ -177,4 +229,56 @@ mod tests {
         let _chunk_string = format!("{}", chunk_type_1);
         let _are_chunks_equal = chunk_type_1 == chunk_type_2;
     }
+
+    #[test]
+    pub fn test_set_critical() {
+        let chunk = ChunkType::from_str("RuSt").unwrap().set_critical(false);
+        assert!(!chunk.is_critical());
+        assert_eq!(&chunk.to_string(), "ruSt");
+
+        let chunk = chunk.set_critical(true);
+        assert!(chunk.is_critical());
+        assert_eq!(&chunk.to_string(), "RuSt");
+    }
+
+    #[test]
+    pub fn test_set_public() {
+        let chunk = ChunkType::from_str("RuSt").unwrap().set_public(true);
+        assert!(chunk.is_public());
+        assert_eq!(&chunk.to_string(), "RUSt");
+
+        let chunk = chunk.set_public(false);
+        assert!(!chunk.is_public());
+        assert_eq!(&chunk.to_string(), "RuSt");
+    }
+
+    #[test]
+    pub fn test_set_reserved_valid() {
+        let chunk = ChunkType::from_str("Rust").unwrap().set_reserved_valid(true);
+        assert!(chunk.is_reserved_bit_valid());
+        assert_eq!(&chunk.to_string(), "RuSt");
+    }
+
+    #[test]
+    pub fn test_set_safe_to_copy() {
+        let chunk = ChunkType::from_str("RuST").unwrap().set_safe_to_copy(true);
+        assert!(chunk.is_safe_to_copy());
+        assert_eq!(&chunk.to_string(), "RuSt");
+
+        let chunk = chunk.set_safe_to_copy(false);
+        assert!(!chunk.is_safe_to_copy());
+        assert_eq!(&chunk.to_string(), "RuST");
+    }
+
+    #[test]
+    pub fn test_from_properties() {
+        let chunk = ChunkType::from_properties(['r', 'u', 's', 't'], true, false, true);
+
+        assert_eq!(&chunk.to_string(), "RuSt");
+        assert!(chunk.is_critical());
+        assert!(!chunk.is_public());
+        assert!(chunk.is_reserved_bit_valid());
+        assert!(chunk.is_safe_to_copy());
+        assert!(chunk.is_valid());
+    }
 }
@@ -0,0 +1,91 @@
+use crate::{Error, Result, StrError};
+use std::convert::TryInto;
+
+/// Converts a typed value into the raw bytes stored in a chunk's data field.
+pub trait Encode {
+    fn encode(&self) -> Vec<u8>;
+}
+
+/// Parses a typed value back out of a chunk's raw data bytes.
+pub trait Decode: Sized {
+    fn decode(bytes: &[u8]) -> Result<Self>;
+}
+
+impl Encode for String {
+    fn encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+impl Decode for String {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        String::from_utf8(bytes.to_vec()).map_err(Error::from)
+    }
+}
+
+impl Encode for Vec<u8> {
+    fn encode(&self) -> Vec<u8> {
+        self.clone()
+    }
+}
+
+impl Decode for Vec<u8> {
+    fn decode(bytes: &[u8]) -> Result<Self> {
+        Ok(bytes.to_vec())
+    }
+}
+
+macro_rules! impl_int_codec {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Encode for $t {
+                fn encode(&self) -> Vec<u8> {
+                    self.to_be_bytes().to_vec()
+                }
+            }
+
+            impl Decode for $t {
+                fn decode(bytes: &[u8]) -> Result<Self> {
+                    let array: [u8; std::mem::size_of::<$t>()] = bytes
+                        .try_into()
+                        .map_err(|_| -> Error { Box::new(StrError("Invalid byte length for integer")) })?;
+
+                    Ok(<$t>::from_be_bytes(array))
+                }
+            }
+        )*
+    };
+}
+
+impl_int_codec!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_round_trip() {
+        let value = String::from("hello chunk");
+        let decoded = String::decode(&value.encode()).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let value: Vec<u8> = vec![1, 2, 3, 4];
+        let decoded = Vec::<u8>::decode(&value.encode()).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_u32_round_trip() {
+        let value: u32 = 0xdeadbeef;
+        let decoded = u32::decode(&value.encode()).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn test_int_decode_wrong_length() {
+        assert!(u32::decode(&[0, 1]).is_err());
+    }
+}
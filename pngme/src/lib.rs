@@ -0,0 +1,25 @@
+mod chunk;
+mod chunk_reader;
+mod chunk_type;
+mod codec;
+mod png;
+
+pub use chunk::Chunk;
+pub use chunk_reader::ChunkReader;
+pub use chunk_type::ChunkType;
+pub use codec::{Decode, Encode};
+pub use png::Png;
+
+pub type Error = Box<dyn std::error::Error>;
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub struct StrError(pub &'static str);
+
+impl std::fmt::Display for StrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for StrError {}
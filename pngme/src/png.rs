@@ -0,0 +1,276 @@
+use crate::chunk::Chunk;
+use crate::{Error, Result, StrError};
+use std::convert::{TryFrom, TryInto};
+
+/// A whole PNG file: the 8-byte signature plus an ordered list of chunks.
+#[derive(Debug, Clone)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+    /// Builds a `Png` from already-decoded chunks, in the order given.
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    /// Appends a chunk to the end of the chunk list.
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    /// Removes and returns the first chunk whose type matches `chunk_type`.
+    pub fn remove_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let position = self
+            .chunks
+            .iter()
+            .position(|chunk| chunk.chunk_type().to_string() == chunk_type)
+            .ok_or_else(|| -> Error { Box::new(StrError("Chunk type not found")) })?;
+
+        Ok(self.chunks.remove(position))
+    }
+
+    /// Returns the first chunk whose type matches `chunk_type`, if any.
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|chunk| chunk.chunk_type().to_string() == chunk_type)
+    }
+
+    /// Returns the chunks in file order.
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    /// Serializes the signature followed by every chunk, in order.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        Self::STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(|chunk| chunk.as_bytes()))
+            .collect()
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+
+    /// Validates the 8-byte signature, then walks the rest of the buffer
+    /// parsing one length-delimited chunk at a time until the buffer is
+    /// exhausted. An `IEND` chunk, if present, ends parsing early and any
+    /// bytes after it are ignored — `IEND` is accepted as a terminator but
+    /// not required. On a malformed chunk, returns the underlying
+    /// `Chunk::try_from` error annotated with the byte offset it started at.
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < Self::STANDARD_HEADER.len() {
+            return Err(Box::new(StrError("Buffer too short for PNG signature")));
+        }
+
+        let (signature, mut rest) = bytes.split_at(Self::STANDARD_HEADER.len());
+        if signature != Self::STANDARD_HEADER {
+            return Err(Box::new(StrError("Invalid PNG signature")));
+        }
+
+        let mut offset = Self::STANDARD_HEADER.len();
+        let mut chunks = Vec::new();
+
+        while !rest.is_empty() {
+            if rest.len() < 12 {
+                return Err(Box::new(StrError("Truncated chunk at end of buffer")));
+            }
+
+            let data_length = u32::from_be_bytes(rest[0..4].try_into().unwrap()) as usize;
+            let chunk_length = 12 + data_length;
+
+            if rest.len() < chunk_length {
+                return Err(Box::new(StrError("Truncated chunk at end of buffer")));
+            }
+
+            let (chunk_bytes, remainder) = rest.split_at(chunk_length);
+            let chunk = Chunk::try_from(chunk_bytes)
+                .map_err(|err| -> Error { format!("Invalid chunk at offset {}: {}", offset, err).into() })?;
+
+            let is_iend = chunk.chunk_type().to_string() == "IEND";
+            chunks.push(chunk);
+
+            offset += chunk_length;
+            rest = remainder;
+
+            if is_iend {
+                break;
+            }
+        }
+
+        Ok(Self { chunks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Chunk {
+        let chunk_type = ChunkType::from_str(chunk_type).unwrap();
+        let data: Vec<u8> = data.bytes().collect();
+
+        Chunk::new(chunk_type, data)
+    }
+
+    fn testing_chunks() -> Vec<Chunk> {
+        vec![
+            chunk_from_strings("FrSt", "I am the first chunk"),
+            chunk_from_strings("miDl", "I am another chunk"),
+            chunk_from_strings("LASt", "I am the last chunk"),
+        ]
+    }
+
+    fn testing_png() -> Png {
+        Png::from_chunks(testing_chunks())
+    }
+
+    #[test]
+    fn test_from_chunks() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_valid_png_from_bytes() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref()).unwrap();
+
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = [13, 80, 78, 71, 13, 10, 26, 10]
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_invalid_chunk() {
+        let mut chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        // Corrupt the CRC of the first chunk ("FrSt", 12 + 20 data bytes long),
+        // so its last byte sits at index 31.
+        let first_chunk_crc_last_byte = 31;
+        chunk_bytes[first_chunk_crc_last_byte] =
+            chunk_bytes[first_chunk_crc_last_byte].wrapping_add(1);
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let png = Png::try_from(bytes.as_ref());
+
+        assert!(png.is_err());
+    }
+
+    #[test]
+    fn test_png_trait_impls() {
+        let chunk_bytes: Vec<u8> = testing_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.as_bytes())
+            .collect();
+
+        let bytes: Vec<u8> = Png::STANDARD_HEADER
+            .iter()
+            .chain(chunk_bytes.iter())
+            .copied()
+            .collect();
+
+        let _png: Png = TryFrom::try_from(bytes.as_ref()).unwrap();
+    }
+
+    #[test]
+    fn test_list_chunks() {
+        let png = testing_png();
+        let chunks = png.chunks();
+
+        assert_eq!(chunks.len(), 3);
+    }
+
+    #[test]
+    fn test_chunk_by_type() {
+        let png = testing_png();
+        let chunk = png.chunk_by_type("FrSt").unwrap();
+
+        assert_eq!(chunk.chunk_type().to_string(), String::from("FrSt"));
+        assert_eq!(chunk.data_as_string().unwrap(), String::from("I am the first chunk"));
+    }
+
+    #[test]
+    fn test_chunk_by_type_missing() {
+        let png = testing_png();
+        assert!(png.chunk_by_type("NoNo").is_none());
+    }
+
+    #[test]
+    fn test_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message"));
+
+        let chunk = png.chunk_by_type("TeSt").unwrap();
+
+        assert_eq!(chunk.chunk_type().to_string(), String::from("TeSt"));
+        assert_eq!(chunk.data_as_string().unwrap(), String::from("Message"));
+    }
+
+    #[test]
+    fn test_remove_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message"));
+
+        let chunk = png.remove_chunk("TeSt").unwrap();
+
+        assert_eq!(chunk.chunk_type().to_string(), String::from("TeSt"));
+        assert_eq!(chunk.data_as_string().unwrap(), String::from("Message"));
+        assert!(png.chunk_by_type("TeSt").is_none());
+    }
+
+    #[test]
+    fn test_remove_chunk_missing() {
+        let mut png = testing_png();
+        assert!(png.remove_chunk("NoNo").is_err());
+    }
+
+    #[test]
+    fn test_png_from_chunks_as_bytes() {
+        let png = testing_png();
+        let png = Png::try_from(png.as_bytes().as_ref()).unwrap();
+
+        assert_eq!(png.chunks().len(), 3);
+    }
+}